@@ -65,6 +65,16 @@ pub(crate) fn apply_format_settings_from_cli(
         .opt_value_from_str("--semicolons")
         .map_err(|source| CliDiagnostic::parse_error("--semicolons", source))?;
 
+    let jsx_quote_style = session
+        .args
+        .opt_value_from_str("--jsx-quote-style")
+        .map_err(|source| CliDiagnostic::parse_error("--jsx-quote-style", source))?;
+
+    let arrow_parentheses = session
+        .args
+        .opt_value_from_str("--arrow-parentheses")
+        .map_err(|source| CliDiagnostic::parse_error("--arrow-parentheses", source))?;
+
     let javascript = configuration
         .javascript
         .get_or_insert_with(JavascriptConfiguration::default);
@@ -88,6 +98,14 @@ pub(crate) fn apply_format_settings_from_cli(
         javascript_formatter.semicolons = semicolons;
     }
 
+    if let Some(jsx_quote_style) = jsx_quote_style {
+        javascript_formatter.jsx_quote_style = jsx_quote_style;
+    }
+
+    if let Some(arrow_parentheses) = arrow_parentheses {
+        javascript_formatter.arrow_parentheses = arrow_parentheses;
+    }
+
     Ok(())
 }
 
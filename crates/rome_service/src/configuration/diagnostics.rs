@@ -0,0 +1,74 @@
+use std::fmt;
+
+/// A series of errors that can be thrown while computing or loading the configuration.
+#[derive(Debug)]
+pub enum ConfigurationDiagnostic {
+    /// Thrown when `rome.json` already exists and the user tried to create a new one.
+    AlreadyExists,
+
+    /// Thrown when the configuration fails to serialize to JSON.
+    SerializationError,
+
+    /// Thrown when `vcs.useIgnoreFile` is `true` while `vcs.enabled` is `false`.
+    DisabledVcs,
+
+    /// Thrown when the VCS integration is enabled but Rome couldn't locate its root folder.
+    NoVcsFolderFound,
+
+    /// Thrown when the configuration file contains genuinely malformed JSON, i.e. errors
+    /// that aren't just tolerated comments or trailing commas.
+    InvalidConfiguration { path: String, diagnostic_count: usize },
+}
+
+impl ConfigurationDiagnostic {
+    pub fn new_already_exists() -> Self {
+        Self::AlreadyExists
+    }
+
+    pub fn new_serialization_error() -> Self {
+        Self::SerializationError
+    }
+
+    pub fn new_disabled_vcs() -> Self {
+        Self::DisabledVcs
+    }
+
+    pub fn new_no_vcs_folder_found() -> Self {
+        Self::NoVcsFolderFound
+    }
+
+    pub fn new_invalid_configuration(path: impl Into<String>, diagnostic_count: usize) -> Self {
+        Self::InvalidConfiguration {
+            path: path.into(),
+            diagnostic_count,
+        }
+    }
+}
+
+impl fmt::Display for ConfigurationDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AlreadyExists => write!(f, "the configuration file already exists"),
+            Self::SerializationError => write!(f, "could not serialize the configuration"),
+            Self::DisabledVcs => write!(
+                f,
+                "the `vcs` integration is disabled, but `vcs.useIgnoreFile` was set to `true`"
+            ),
+            Self::NoVcsFolderFound => write!(
+                f,
+                "the `vcs` integration is enabled, but Rome couldn't find the folder of the VCS"
+            ),
+            Self::InvalidConfiguration {
+                path,
+                diagnostic_count,
+            } => {
+                write!(
+                    f,
+                    "the configuration file at {path} contains invalid JSON ({diagnostic_count} parsing error(s))"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigurationDiagnostic {}
@@ -0,0 +1,156 @@
+use crate::configuration::StringSet;
+use rome_js_formatter::context::{QuoteProperties, QuoteStyle, Semicolons, TrailingComma};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// A set of options applied to the JavaScript files
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct JavascriptConfiguration {
+    /// A list of global bindings that should be ignored by the analyzer, in addition to the
+    /// globals defined by each language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub globals: Option<StringSet>,
+
+    /// Formatting options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatter: Option<JavascriptFormatter>,
+}
+
+/// Options that changes how the JavaScript formatter behaves
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct JavascriptFormatter {
+    /// The style for quotation marks used in string literals and object property names.
+    pub quote_style: QuoteStyle,
+
+    /// The style for quotation marks used in JSX attribute values.
+    pub jsx_quote_style: QuoteStyle,
+
+    /// When properties in objects are quoted.
+    pub quote_properties: QuoteProperties,
+
+    /// Print trailing commas wherever possible in multi-line comma-separated syntactic
+    /// structures.
+    pub trailing_comma: TrailingComma,
+
+    /// Whether the formatter prints semicolons for all statements or only in places where
+    /// they are necessary because of ASI.
+    pub semicolons: Semicolons,
+
+    /// Whether to add non-necessary parentheses around a sole arrow function parameter.
+    pub arrow_parentheses: ArrowParentheses,
+}
+
+/// Whether to add non-necessary parentheses around a sole arrow function parameter.
+///
+/// A lone identifier parameter with no type annotation, default value, destructuring or rest
+/// pattern can drop its parentheses (`x => x`); any other kind of parameter keeps them
+/// regardless of this setting.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum ArrowParentheses {
+    #[default]
+    Always,
+    AsNeeded,
+}
+
+impl FromStr for ArrowParentheses {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "always" => Ok(Self::Always),
+            "as-needed" => Ok(Self::AsNeeded),
+            _ => Err("Value not supported for ArrowParentheses"),
+        }
+    }
+}
+
+/// A simplified description of a single arrow-function parameter, enough to decide whether
+/// [ArrowParentheses::AsNeeded] can drop its parentheses, as decided by
+/// [ArrowParentheses::is_required_for]. Nothing in this crate builds it from a syntax node
+/// or prints arrow functions.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ArrowFunctionParameter {
+    pub has_type_annotation: bool,
+    pub has_default_value: bool,
+    pub is_destructured: bool,
+    pub is_rest: bool,
+}
+
+impl ArrowFunctionParameter {
+    /// A parameter that is a bare identifier, with no type annotation, default value,
+    /// destructuring pattern or rest marker.
+    pub fn is_plain_identifier(self) -> bool {
+        !self.has_type_annotation
+            && !self.has_default_value
+            && !self.is_destructured
+            && !self.is_rest
+    }
+}
+
+impl ArrowParentheses {
+    /// Returns whether parentheses should be kept around a single arrow-function parameter
+    /// shaped like `param`.
+    pub fn is_required_for(self, param: ArrowFunctionParameter) -> bool {
+        match self {
+            ArrowParentheses::Always => true,
+            ArrowParentheses::AsNeeded => !param.is_plain_identifier(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn always_keeps_parentheses_regardless_of_parameter_shape() {
+        assert!(ArrowParentheses::Always.is_required_for(ArrowFunctionParameter::default()));
+    }
+
+    #[test]
+    fn as_needed_drops_parentheses_for_a_plain_identifier() {
+        assert!(!ArrowParentheses::AsNeeded.is_required_for(ArrowFunctionParameter::default()));
+    }
+
+    #[test]
+    fn as_needed_keeps_parentheses_for_a_typed_parameter() {
+        let param = ArrowFunctionParameter {
+            has_type_annotation: true,
+            ..ArrowFunctionParameter::default()
+        };
+        assert!(ArrowParentheses::AsNeeded.is_required_for(param));
+    }
+
+    #[test]
+    fn as_needed_keeps_parentheses_for_a_destructured_parameter() {
+        let param = ArrowFunctionParameter {
+            is_destructured: true,
+            ..ArrowFunctionParameter::default()
+        };
+        assert!(ArrowParentheses::AsNeeded.is_required_for(param));
+    }
+
+    #[test]
+    fn as_needed_keeps_parentheses_for_a_rest_parameter() {
+        let param = ArrowFunctionParameter {
+            is_rest: true,
+            ..ArrowFunctionParameter::default()
+        };
+        assert!(ArrowParentheses::AsNeeded.is_required_for(param));
+    }
+
+    #[test]
+    fn as_needed_keeps_parentheses_for_a_defaulted_parameter() {
+        let param = ArrowFunctionParameter {
+            has_default_value: true,
+            ..ArrowFunctionParameter::default()
+        };
+        assert!(ArrowParentheses::AsNeeded.is_required_for(param));
+    }
+}
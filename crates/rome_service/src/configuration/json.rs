@@ -0,0 +1,37 @@
+use crate::configuration::PlainIndentStyle;
+use serde::{Deserialize, Serialize};
+
+/// A set of options applied to the JSON files
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct JsonConfiguration {
+    /// Formatting options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatter: Option<JsonFormatter>,
+
+    /// Allow parsing comments and trailing commas in `.json`/`.jsonc` source files.
+    ///
+    /// This is independent from the leniency Rome already grants to `rome.json` itself; it
+    /// governs the files the formatter/linter are asked to process.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_comments: Option<bool>,
+}
+
+/// Options that changes how the JSON formatter behaves
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct JsonFormatter {
+    /// The size of the indentation applied to JSON files. Default to 2.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indent_size: Option<u8>,
+
+    /// The indent style applied to JSON files.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indent_style: Option<PlainIndentStyle>,
+
+    /// What's the max width of a line applied to JSON files. Defaults to 80.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_width: Option<u16>,
+}
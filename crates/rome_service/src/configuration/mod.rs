@@ -4,14 +4,10 @@
 //! by language. The language might further options divided by tool.
 
 use crate::{DynRef, WorkspaceError};
-use indexmap::IndexSet;
 use rome_fs::{FileSystem, OpenOptions};
-use serde::de::{SeqAccess, Visitor};
-use serde::ser::SerializeSeq;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use std::io::ErrorKind;
-use std::marker::PhantomData;
 use std::num::NonZeroU64;
 use std::path::{Path, PathBuf};
 use tracing::{error, info};
@@ -20,23 +16,29 @@ pub mod diagnostics;
 mod formatter;
 mod generated;
 mod javascript;
+mod json;
 pub mod linter;
 pub mod organize_imports;
 mod parse;
+mod string_set;
+mod vcs;
 
 pub use crate::configuration::diagnostics::ConfigurationDiagnostic;
 use crate::configuration::generated::push_to_analyzer_rules;
 use crate::configuration::organize_imports::OrganizeImports;
 use crate::settings::{LanguagesSettings, LinterSettings};
 pub use formatter::{FormatterConfiguration, PlainIndentStyle};
-pub use javascript::{JavascriptConfiguration, JavascriptFormatter};
+pub use javascript::{ArrowParentheses, JavascriptConfiguration, JavascriptFormatter};
+pub use json::{JsonConfiguration, JsonFormatter};
 pub use linter::{LinterConfiguration, RuleConfiguration, Rules};
+pub use string_set::StringSet;
+pub use vcs::{VcsClientKind, VcsConfiguration};
 use rome_analyze::{AnalyzerConfiguration, AnalyzerRules};
-use rome_deserialize::json::deserialize_from_json;
+use rome_deserialize::json::deserialize_from_json_ast;
 use rome_deserialize::Deserialized;
 use rome_js_analyze::metadata;
 use rome_json_formatter::context::JsonFormatOptions;
-use rome_json_parser::parse_json;
+use rome_json_parser::{parse_json, parse_json_with_options, JsonParserOptions};
 
 /// The configuration that is contained inside the file `rome.json`
 #[derive(Debug, Deserialize, Serialize)]
@@ -66,6 +68,14 @@ pub struct Configuration {
     /// Specific configuration for the JavaScript language
     #[serde(skip_serializing_if = "Option::is_none")]
     pub javascript: Option<JavascriptConfiguration>,
+
+    /// Specific configuration for the Json language
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub json: Option<JsonConfiguration>,
+
+    /// The configuration of the VCS integration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcs: Option<VcsConfiguration>,
 }
 
 impl Default for Configuration {
@@ -79,6 +89,8 @@ impl Default for Configuration {
             organize_imports: Some(OrganizeImports { enabled: false }),
             formatter: None,
             javascript: None,
+            json: None,
+            vcs: None,
             schema: None,
         }
     }
@@ -90,8 +102,10 @@ impl Configuration {
         "linter",
         "formatter",
         "javascript",
+        "json",
         "$schema",
         "organizeImports",
+        "vcs",
     ];
 }
 
@@ -123,12 +137,8 @@ pub struct FilesConfiguration {
 
     /// A list of Unix shell style patterns. Rome tools will ignore files/folders that will
     /// match these patterns.
-    #[serde(
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::deserialize_set_of_strings",
-        serialize_with = "crate::serialize_set_of_strings"
-    )]
-    pub ignore: Option<IndexSet<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore: Option<StringSet>,
 }
 
 impl FilesConfiguration {
@@ -159,6 +169,18 @@ impl ConfigurationBasePath {
     }
 }
 
+/// The [JsonParserOptions] used to parse `rome.json`.
+///
+/// The configuration file is allowed to contain `//` and `/* */` comments and
+/// trailing commas in objects and arrays (JSONC), so that users can document why a rule
+/// is disabled. This is shared by the CLI and the LSP, since both go through [load_config].
+pub fn config_parser_options() -> JsonParserOptions {
+    JsonParserOptions {
+        allow_comments: true,
+        allow_trailing_commas: true,
+    }
+}
+
 /// Load the configuration from the file system.
 ///
 /// The configuration file will be read from the `file_system`. A [base path](ConfigurationBasePath) should be provided.
@@ -208,8 +230,27 @@ pub fn load_config(
                     );
                 }
 
-                let deserialized = deserialize_from_json::<Configuration>(&buffer)
+                let parsed = parse_json_with_options(&buffer, config_parser_options());
+                // `JsonParserOptions` tolerates comments and trailing commas, but genuinely
+                // malformed JSON (unbalanced braces, stray tokens, ...) still produces parse
+                // diagnostics that `deserialize_from_json_ast` won't rediscover from the
+                // (possibly error-recovered) tree, so they have to be checked here.
+                if !parsed.diagnostics().is_empty() {
+                    return Err(WorkspaceError::Configuration(
+                        ConfigurationDiagnostic::new_invalid_configuration(
+                            configuration_path.display().to_string(),
+                            parsed.diagnostics().len(),
+                        ),
+                    ));
+                }
+
+                let mut deserialized = deserialize_from_json_ast::<Configuration>(&parsed.syntax())
                     .with_file_path(&configuration_path.display().to_string());
+
+                if let Some(configuration) = deserialized.deserialized_mut() {
+                    resolve_vcs_ignore_file(file_system, configuration, &configuration_directory)?;
+                }
+
                 Ok(Some(deserialized))
             }
             Err(err) => {
@@ -251,6 +292,49 @@ pub fn load_config(
     }
 }
 
+/// If the `vcs` section is enabled and configured to use the ignore file, walks up from
+/// `configuration_directory` to find the VCS root, reads its ignore file (e.g. `.gitignore`)
+/// and merges the resulting patterns into `configuration.files.ignore`.
+///
+/// Called from [load_config] itself, which is the single code path shared by the CLI and
+/// the LSP, so both pick up the merged ignore patterns automatically.
+fn resolve_vcs_ignore_file(
+    file_system: &DynRef<dyn FileSystem>,
+    configuration: &mut Configuration,
+    configuration_directory: &Path,
+) -> Result<(), WorkspaceError> {
+    let Some(vcs) = configuration.vcs.as_ref() else {
+        return Ok(());
+    };
+
+    if !vcs.use_ignore_file {
+        return Ok(());
+    }
+
+    if !vcs.enabled {
+        return Err(WorkspaceError::Configuration(
+            ConfigurationDiagnostic::new_disabled_vcs(),
+        ));
+    }
+
+    let client_kind = vcs.client_kind.unwrap_or_default();
+    let Some(vcs_root) = vcs::find_vcs_root(file_system, configuration_directory, client_kind)
+    else {
+        return Err(WorkspaceError::Configuration(
+            ConfigurationDiagnostic::new_no_vcs_folder_found(),
+        ));
+    };
+
+    let patterns = vcs::read_vcs_ignore_patterns(file_system, &vcs_root, client_kind);
+    let files = configuration
+        .files
+        .get_or_insert_with(FilesConfiguration::default);
+    let ignore = files.ignore.get_or_insert_with(StringSet::default);
+    ignore.extend(patterns);
+
+    Ok(())
+}
+
 /// Creates a new configuration on file system
 ///
 /// ## Errors
@@ -285,6 +369,8 @@ pub fn create_config(
         WorkspaceError::Configuration(ConfigurationDiagnostic::new_serialization_error())
     })?;
 
+    // The configuration we just serialized never contains comments or trailing commas,
+    // so it's parsed with the default (strict) options rather than `config_parser_options`.
     let parsed = parse_json(&contents);
     let formatted =
         rome_json_formatter::format_node(JsonFormatOptions::default(), &parsed.syntax())?
@@ -298,70 +384,6 @@ pub fn create_config(
     Ok(())
 }
 
-/// Some documentation
-pub fn deserialize_set_of_strings<'de, D>(
-    deserializer: D,
-) -> Result<Option<IndexSet<String>>, D::Error>
-where
-    D: serde::de::Deserializer<'de>,
-{
-    struct IndexVisitor {
-        marker: PhantomData<fn() -> Option<IndexSet<String>>>,
-    }
-
-    impl IndexVisitor {
-        fn new() -> Self {
-            IndexVisitor {
-                marker: PhantomData,
-            }
-        }
-    }
-
-    impl<'de> Visitor<'de> for IndexVisitor {
-        type Value = Option<IndexSet<String>>;
-
-        // Format a message stating what data this Visitor expects to receive.
-        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-            formatter.write_str("expecting a sequence")
-        }
-
-        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
-        where
-            A: SeqAccess<'de>,
-        {
-            let mut index_set = IndexSet::with_capacity(seq.size_hint().unwrap_or(0));
-
-            while let Some(value) = seq.next_element()? {
-                index_set.insert(value);
-            }
-
-            Ok(Some(index_set))
-        }
-    }
-
-    deserializer.deserialize_seq(IndexVisitor::new())
-}
-
-pub fn serialize_set_of_strings<S>(
-    set_of_strings: &Option<IndexSet<String>>,
-    s: S,
-) -> Result<S::Ok, S::Error>
-where
-    S: serde::ser::Serializer,
-{
-    if let Some(set_of_strings) = set_of_strings {
-        let mut sequence = s.serialize_seq(Some(set_of_strings.len()))?;
-        let iter = set_of_strings.into_iter();
-        for global in iter {
-            sequence.serialize_element(global)?;
-        }
-
-        sequence.end()
-    } else {
-        s.serialize_none()
-    }
-}
-
 /// Converts a [WorkspaceSettings] into a suited [configuration for the analyzer].
 ///
 /// The function needs access to a filter, in order to have an easy access to the [metadata] of the
@@ -422,3 +444,64 @@ where
         rules: analyzer_rules,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rome_fs::MemoryFileSystem;
+
+    fn load_from_memory(content: &str) -> LoadConfig {
+        let mut memory_fs = MemoryFileSystem::default();
+        memory_fs.insert(PathBuf::from("rome.json"), content.as_bytes().to_vec());
+        let file_system: DynRef<dyn FileSystem> = DynRef::Borrowed(&memory_fs);
+        load_config(&file_system, ConfigurationBasePath::FromUser(PathBuf::new()))
+    }
+
+    #[test]
+    fn accepts_comments_and_trailing_commas() {
+        let content = r#"{
+            // the formatter is on, trailing commas below are fine too
+            "formatter": {
+                "enabled": true,
+            },
+        }"#;
+
+        let deserialized = load_from_memory(content)
+            .expect("a JSONC rome.json should load without error")
+            .expect("rome.json exists");
+
+        assert!(deserialized.deserialized().is_some());
+    }
+
+    #[test]
+    fn rejects_genuinely_malformed_json() {
+        let content = r#"{ "formatter": { "enabled": true "#;
+
+        let result = load_from_memory(content);
+
+        assert!(matches!(
+            result,
+            Err(WorkspaceError::Configuration(
+                ConfigurationDiagnostic::InvalidConfiguration { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn create_config_emits_comment_free_json() {
+        let mut memory_fs = MemoryFileSystem::default();
+        let mut file_system: DynRef<dyn FileSystem> = DynRef::Borrowed(&memory_fs);
+
+        create_config(&mut file_system, Configuration::default()).unwrap();
+
+        let options = OpenOptions::default().read(true);
+        let mut file = memory_fs
+            .open_with_options(Path::new("rome.json"), options)
+            .unwrap();
+        let mut content = String::new();
+        file.read_to_string(&mut content).unwrap();
+
+        assert!(!content.contains("//"));
+        assert!(!content.contains("/*"));
+    }
+}
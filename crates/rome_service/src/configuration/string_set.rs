@@ -0,0 +1,157 @@
+use indexmap::IndexSet;
+use serde::de::{SeqAccess, Visitor};
+use serde::ser::SerializeSeq;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+/// A set of unique strings.
+///
+/// It deserializes from and serializes to a plain JSON array, while guaranteeing that each
+/// value only appears once and preserving insertion order. This is used throughout the
+/// configuration wherever a deduplicated list of strings is needed, e.g. ignore patterns or
+/// globals.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct StringSet(IndexSet<String>);
+
+impl StringSet {
+    pub fn new(set: IndexSet<String>) -> Self {
+        Self(set)
+    }
+
+    /// Inserts `value`, returning `true` if it wasn't already present.
+    pub fn insert(&mut self, value: impl Into<String>) -> bool {
+        self.0.insert(value.into())
+    }
+
+    /// Extends the set with `values`, silently dropping any duplicate.
+    pub fn extend(&mut self, values: impl IntoIterator<Item = String>) {
+        self.0.extend(values);
+    }
+
+    pub fn iter(&self) -> indexmap::set::Iter<String> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Deref for StringSet {
+    type Target = IndexSet<String>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl FromIterator<String> for StringSet {
+    fn from_iter<T: IntoIterator<Item = String>>(iter: T) -> Self {
+        Self(IndexSet::from_iter(iter))
+    }
+}
+
+impl<const N: usize> From<[&str; N]> for StringSet {
+    fn from(value: [&str; N]) -> Self {
+        value.into_iter().map(String::from).collect()
+    }
+}
+
+impl Serialize for StringSet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        let mut sequence = serializer.serialize_seq(Some(self.0.len()))?;
+        for value in &self.0 {
+            sequence.serialize_element(value)?;
+        }
+        sequence.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for StringSet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct StringSetVisitor {
+            marker: PhantomData<fn() -> StringSet>,
+        }
+
+        impl<'de> Visitor<'de> for StringSetVisitor {
+            type Value = StringSet;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a sequence of strings")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut set = IndexSet::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(value) = seq.next_element()? {
+                    set.insert(value);
+                }
+                Ok(StringSet(set))
+            }
+        }
+
+        deserializer.deserialize_seq(StringSetVisitor {
+            marker: PhantomData,
+        })
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for StringSet {
+    fn schema_name() -> String {
+        "StringSet".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <Vec<String>>::json_schema(gen)
+    }
+
+    fn is_referenceable() -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_from_a_json_array() {
+        let set: StringSet = serde_json::from_str(r#"["a", "b", "c"]"#).unwrap();
+        assert_eq!(set, StringSet::from(["a", "b", "c"]));
+    }
+
+    #[test]
+    fn deserialization_deduplicates_while_preserving_order() {
+        let set: StringSet = serde_json::from_str(r#"["a", "b", "a"]"#).unwrap();
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn serializes_back_to_a_json_array() {
+        let set = StringSet::from(["a", "b", "c"]);
+        assert_eq!(serde_json::to_string(&set).unwrap(), r#"["a","b","c"]"#);
+    }
+
+    #[test]
+    fn roundtrips_through_json() {
+        let set = StringSet::from(["one", "two"]);
+        let json = serde_json::to_string(&set).unwrap();
+        let roundtripped: StringSet = serde_json::from_str(&json).unwrap();
+        assert_eq!(set, roundtripped);
+    }
+}
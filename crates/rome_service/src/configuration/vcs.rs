@@ -0,0 +1,176 @@
+use crate::DynRef;
+use rome_fs::{FileSystem, OpenOptions};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Set of properties to integrate Rome with a VCS software.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct VcsConfiguration {
+    /// Whether Rome should integrate itself with the VCS client
+    pub enabled: bool,
+
+    /// The kind of client.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_kind: Option<VcsClientKind>,
+
+    /// Whether Rome should use the `.gitignore` file of the root of the VCS client, if
+    /// found, and ignore the files and folders listed in it.
+    pub use_ignore_file: bool,
+}
+
+impl VcsConfiguration {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] =
+        &["enabled", "clientKind", "useIgnoreFile"];
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+}
+
+/// The kind of VCS client that Rome can integrate with.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum VcsClientKind {
+    #[default]
+    Git,
+}
+
+impl VcsClientKind {
+    /// The name of the directory/file this client uses to mark the root of a repository.
+    const fn root_marker(self) -> &'static str {
+        match self {
+            VcsClientKind::Git => ".git",
+        }
+    }
+
+    /// The name of the ignore file this client reads at its repository root.
+    const fn ignore_file_name(self) -> &'static str {
+        match self {
+            VcsClientKind::Git => ".gitignore",
+        }
+    }
+}
+
+/// Walks upwards from `starting_point`, returning the first ancestor directory (inclusive)
+/// that contains the root marker of `client_kind` (e.g. `.git`).
+pub fn find_vcs_root(
+    file_system: &DynRef<dyn FileSystem>,
+    starting_point: &Path,
+    client_kind: VcsClientKind,
+) -> Option<PathBuf> {
+    let marker = client_kind.root_marker();
+    let mut current = Some(starting_point);
+    while let Some(directory) = current {
+        if file_system.path_exists(&directory.join(marker)) {
+            return Some(directory.to_path_buf());
+        }
+        current = directory.parent();
+    }
+    None
+}
+
+/// Reads the ignore file at the root of `vcs_root` (e.g. `.gitignore`) through `file_system`
+/// and turns each of its non-comment, non-empty lines into a Unix shell style glob pattern,
+/// ready to be merged into [crate::configuration::FilesConfiguration::ignore]. Negated
+/// (`!`) lines are skipped and produce no pattern.
+pub fn read_vcs_ignore_patterns(
+    file_system: &DynRef<dyn FileSystem>,
+    vcs_root: &Path,
+    client_kind: VcsClientKind,
+) -> Vec<String> {
+    let ignore_file_path = vcs_root.join(client_kind.ignore_file_name());
+    let options = OpenOptions::default().read(true);
+    let Ok(mut file) = file_system.open_with_options(&ignore_file_path, options) else {
+        return Vec::new();
+    };
+
+    let mut contents = String::new();
+    if file.read_to_string(&mut contents).is_err() {
+        return Vec::new();
+    }
+
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(gitignore_line_to_glob)
+        .collect()
+}
+
+/// Converts a single `.gitignore` line into the Unix shell style glob pattern used by
+/// Rome's own ignore matching, or `None` if the line can't be represented as a single glob
+/// (namely, negated patterns, which would require un-ignoring a previous match).
+fn gitignore_line_to_glob(line: &str) -> Option<String> {
+    // A negated pattern re-includes a previously ignored path; our ignore set has no concept
+    // of un-ignoring; skip it rather than turning it into an incorrect plain ignore pattern.
+    if line.starts_with('!') {
+        return None;
+    }
+
+    // A trailing slash means "only match directories"; our matcher doesn't distinguish
+    // files from directories, so matching everything under it is the closest equivalent.
+    let (pattern, matches_directory) = match line.strip_suffix('/') {
+        Some(pattern) => (pattern, true),
+        None => (line, false),
+    };
+
+    // Per `.gitignore` semantics, a `/` anywhere but the end (including an explicit leading
+    // slash) anchors the pattern to the VCS root; a pattern with no remaining separator isn't
+    // anchored and can match at any depth.
+    let anchored = pattern.contains('/');
+
+    let pattern = pattern.strip_prefix('/').unwrap_or(pattern);
+    let pattern = if matches_directory {
+        format!("{pattern}/**")
+    } else {
+        pattern.to_string()
+    };
+
+    Some(if anchored {
+        pattern
+    } else {
+        format!("**/{pattern}")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unanchored_directory_pattern_matches_any_depth() {
+        assert_eq!(
+            gitignore_line_to_glob("node_modules/"),
+            Some("**/node_modules/**".to_string())
+        );
+    }
+
+    #[test]
+    fn unanchored_file_pattern_matches_any_depth() {
+        assert_eq!(
+            gitignore_line_to_glob("*.log"),
+            Some("**/*.log".to_string())
+        );
+    }
+
+    #[test]
+    fn leading_slash_anchors_pattern_to_root() {
+        assert_eq!(gitignore_line_to_glob("/dist/"), Some("dist/**".to_string()));
+    }
+
+    #[test]
+    fn interior_slash_anchors_pattern_to_root() {
+        assert_eq!(
+            gitignore_line_to_glob("src/generated"),
+            Some("src/generated".to_string())
+        );
+    }
+
+    #[test]
+    fn negated_patterns_are_skipped() {
+        assert_eq!(gitignore_line_to_glob("!keep.log"), None);
+    }
+}
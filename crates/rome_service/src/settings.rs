@@ -0,0 +1,206 @@
+use crate::configuration::{
+    ArrowParentheses, Configuration, JavascriptConfiguration, JsonConfiguration,
+    LinterConfiguration, PlainIndentStyle, Rules,
+};
+use indexmap::IndexSet;
+use rome_js_formatter::context::{QuoteProperties, QuoteStyle, Semicolons, TrailingComma};
+
+/// Global settings for the whole workspace
+#[derive(Debug, Default)]
+pub struct WorkspaceSettings {
+    /// Settings for the linter
+    pub linter: LinterSettings,
+    /// Settings scoped to a specific language
+    pub languages: LanguagesSettings,
+}
+
+impl WorkspaceSettings {
+    /// Updates these settings from `configuration`, the way the CLI/LSP do once a `rome.json`
+    /// has been loaded.
+    pub fn merge_with_configuration(&mut self, configuration: &Configuration) {
+        self.linter = LinterSettings::from(configuration.linter.clone());
+        self.languages.merge_with_configuration(configuration);
+    }
+}
+
+/// A view of the linter settings, built from [LinterConfiguration]
+#[derive(Debug)]
+pub struct LinterSettings {
+    pub enabled: bool,
+    pub rules: Option<Rules>,
+}
+
+impl Default for LinterSettings {
+    /// Mirrors [Configuration::default], where an absent `linter` section means the linter
+    /// is enabled, not disabled.
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            rules: None,
+        }
+    }
+}
+
+impl From<Option<LinterConfiguration>> for LinterSettings {
+    fn from(configuration: Option<LinterConfiguration>) -> Self {
+        let Some(configuration) = configuration else {
+            return Self::default();
+        };
+        Self {
+            enabled: configuration.enabled,
+            rules: configuration.rules,
+        }
+    }
+}
+
+/// Settings scoped by language, mirroring the per-language sections of [Configuration]
+#[derive(Debug, Default)]
+pub struct LanguagesSettings {
+    pub javascript: JavascriptLanguageSettings,
+    pub json: JsonLanguageSettings,
+}
+
+impl LanguagesSettings {
+    fn merge_with_configuration(&mut self, configuration: &Configuration) {
+        self.javascript = JavascriptLanguageSettings::from(configuration.javascript.clone());
+        self.json = JsonLanguageSettings::from(configuration.json.clone());
+    }
+}
+
+/// The settings the JavaScript analyzer/formatter read, built from [JavascriptConfiguration]
+#[derive(Debug, Default)]
+pub struct JavascriptLanguageSettings {
+    pub globals: Option<IndexSet<String>>,
+    pub quote_style: Option<QuoteStyle>,
+    pub jsx_quote_style: Option<QuoteStyle>,
+    pub quote_properties: Option<QuoteProperties>,
+    pub trailing_comma: Option<TrailingComma>,
+    pub semicolons: Option<Semicolons>,
+    pub arrow_parentheses: Option<ArrowParentheses>,
+}
+
+impl From<Option<JavascriptConfiguration>> for JavascriptLanguageSettings {
+    fn from(configuration: Option<JavascriptConfiguration>) -> Self {
+        let Some(configuration) = configuration else {
+            return Self::default();
+        };
+
+        let formatter = configuration.formatter.as_ref();
+        Self {
+            globals: configuration
+                .globals
+                .map(|globals| globals.iter().cloned().collect()),
+            quote_style: formatter.map(|formatter| formatter.quote_style),
+            jsx_quote_style: formatter.map(|formatter| formatter.jsx_quote_style),
+            quote_properties: formatter.map(|formatter| formatter.quote_properties),
+            trailing_comma: formatter.map(|formatter| formatter.trailing_comma),
+            semicolons: formatter.map(|formatter| formatter.semicolons),
+            arrow_parentheses: formatter.map(|formatter| formatter.arrow_parentheses),
+        }
+    }
+}
+
+/// The settings the JSON analyzer/formatter read, built from [JsonConfiguration]
+#[derive(Debug, Default)]
+pub struct JsonLanguageSettings {
+    /// Whether comments and trailing commas are allowed in the `.json`/`.jsonc` source files
+    /// being formatted or linted
+    pub allow_comments: bool,
+    pub indent_style: Option<PlainIndentStyle>,
+    pub indent_size: Option<u8>,
+    pub line_width: Option<u16>,
+}
+
+impl From<Option<JsonConfiguration>> for JsonLanguageSettings {
+    fn from(configuration: Option<JsonConfiguration>) -> Self {
+        let Some(configuration) = configuration else {
+            return Self::default();
+        };
+
+        let formatter = configuration.formatter.as_ref();
+        Self {
+            allow_comments: configuration.allow_comments.unwrap_or(false),
+            indent_style: formatter.and_then(|formatter| formatter.indent_style),
+            indent_size: formatter.and_then(|formatter| formatter.indent_size),
+            line_width: formatter.and_then(|formatter| formatter.line_width),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configuration::{JavascriptFormatter, JsonFormatter, StringSet};
+
+    #[test]
+    fn linter_settings_default_to_enabled() {
+        let settings = LinterSettings::from(None);
+        assert!(settings.enabled);
+    }
+
+    #[test]
+    fn javascript_settings_default_to_no_globals_or_formatter_overrides() {
+        let settings = JavascriptLanguageSettings::from(None);
+        assert_eq!(settings.globals, None);
+        assert_eq!(settings.quote_style, None);
+    }
+
+    #[test]
+    fn javascript_settings_pick_up_globals_and_formatter_options() {
+        let configuration = JavascriptConfiguration {
+            globals: Some(StringSet::from(["window", "document"])),
+            formatter: Some(JavascriptFormatter {
+                quote_style: QuoteStyle::Single,
+                jsx_quote_style: QuoteStyle::Single,
+                quote_properties: QuoteProperties::Preserve,
+                trailing_comma: TrailingComma::None,
+                semicolons: Semicolons::AsNeeded,
+                arrow_parentheses: ArrowParentheses::AsNeeded,
+            }),
+        };
+
+        let settings = JavascriptLanguageSettings::from(Some(configuration));
+
+        assert_eq!(
+            settings.globals,
+            Some(IndexSet::from([
+                "window".to_string(),
+                "document".to_string()
+            ]))
+        );
+        assert_eq!(settings.quote_style, Some(QuoteStyle::Single));
+        assert_eq!(settings.jsx_quote_style, Some(QuoteStyle::Single));
+        assert_eq!(settings.quote_properties, Some(QuoteProperties::Preserve));
+        assert_eq!(settings.trailing_comma, Some(TrailingComma::None));
+        assert_eq!(settings.semicolons, Some(Semicolons::AsNeeded));
+        assert_eq!(
+            settings.arrow_parentheses,
+            Some(ArrowParentheses::AsNeeded)
+        );
+    }
+
+    #[test]
+    fn json_settings_default_to_disallowing_comments() {
+        let settings = JsonLanguageSettings::from(None);
+        assert!(!settings.allow_comments);
+    }
+
+    #[test]
+    fn json_settings_pick_up_allow_comments_and_formatter_options() {
+        let configuration = JsonConfiguration {
+            allow_comments: Some(true),
+            formatter: Some(JsonFormatter {
+                indent_size: Some(4),
+                indent_style: Some(PlainIndentStyle::Space),
+                line_width: Some(100),
+            }),
+        };
+
+        let settings = JsonLanguageSettings::from(Some(configuration));
+
+        assert!(settings.allow_comments);
+        assert_eq!(settings.indent_size, Some(4));
+        assert_eq!(settings.indent_style, Some(PlainIndentStyle::Space));
+        assert_eq!(settings.line_width, Some(100));
+    }
+}